@@ -5,6 +5,122 @@
 //!  are accepted as values.
 //! See the macro [`fake_enum`] for details.
 
+///
+/// The error returned by the generated `FromStr` impl of a [`fake_enum`] type when the input
+///  matches neither a declared variant name nor the `Name(value)` fallback form.
+///
+/// Since this crate is `no_std`, the offending text is kept inline instead of being owned on
+///  the heap, and is truncated to the last char boundary at or before the internal buffer's
+///  capacity if it is longer than the buffer.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct ParseEnumError {
+    buf: [u8; 32],
+    len: u8,
+}
+
+impl ParseEnumError {
+    #[doc(hidden)]
+    pub fn __new(s: &str) -> Self {
+        let bytes = s.as_bytes();
+        let mut len = bytes.len().min(32);
+        if len < bytes.len() {
+            while len > 0 && (bytes[len] & 0xC0) == 0x80 {
+                len -= 1;
+            }
+        }
+        let mut buf = [0u8; 32];
+        buf[..len].copy_from_slice(&bytes[..len]);
+        Self {
+            buf,
+            len: len as u8,
+        }
+    }
+
+    /// Returns the text that failed to parse, truncated to the first 32 bytes (rounded down to
+    ///  the nearest char boundary) if it was longer.
+    pub fn as_str(&self) -> &str {
+        ::core::str::from_utf8(&self.buf[..self.len as usize]).unwrap_or("")
+    }
+}
+
+impl ::core::fmt::Debug for ParseEnumError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("ParseEnumError").field(&self.as_str()).finish()
+    }
+}
+
+impl ::core::fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_fmt(::core::format_args!(
+            "invalid fake enum variant: {}",
+            self.as_str()
+        ))
+    }
+}
+
+/// Re-exports of crates used by macro-generated code, so that downstream crates don't need a
+///  direct dependency on them just to enable a `fake_enum` feature.
+#[doc(hidden)]
+#[cfg(feature = "serde")]
+pub mod __private {
+    pub use serde;
+}
+
+///
+/// Implemented by every type generated by [`fake_enum`], giving access to the declared variants
+///  in declaration order. Used by [`EnumMap`] to map a declared variant to its position,
+///  regardless of its representation value.
+pub trait FakeEnum: Copy + PartialEq + Sized + 'static {
+    /// Returns every declared variant of this fake enum, in declaration order.
+    fn variants() -> &'static [Self];
+
+    /// Returns this value's position among the declared variants, in declaration order, or
+    ///  `None` if it does not match any declared variant.
+    fn position(self) -> Option<usize> {
+        Self::variants().iter().position(|v| *v == self)
+    }
+}
+
+///
+/// A dense, `const fn`-constructible map from the declared variants of a [`FakeEnum`] type to a
+///  value of type `V`, backed by a fixed-size array indexed by declaration order rather than by
+///  representation value, so large or sparse discriminants stay compact.
+///
+/// `N` must match the number of declared variants of `E`; looking up a key that does not match
+///  any declared variant returns `None` rather than panicking.
+pub struct EnumMap<E, V, const N: usize> {
+    values: [V; N],
+    _marker: ::core::marker::PhantomData<E>,
+}
+
+impl<E: FakeEnum, V, const N: usize> EnumMap<E, V, N> {
+    /// Constructs a map directly from an array of values, one per declared variant of `E`, in
+    ///  declaration order.
+    pub const fn new(values: [V; N]) -> Self {
+        Self {
+            values,
+            _marker: ::core::marker::PhantomData,
+        }
+    }
+
+    /// Returns the value associated with `key`, or `None` if `key` does not match any declared
+    ///  variant of `E`.
+    pub fn get(&self, key: E) -> Option<&V> {
+        key.position().and_then(|i| self.values.get(i))
+    }
+
+    /// Returns a mutable reference to the value associated with `key`, or `None` if `key` does
+    ///  not match any declared variant of `E`.
+    pub fn get_mut(&mut self, key: E) -> Option<&mut V> {
+        key.position().and_then(move |i| self.values.get_mut(i))
+    }
+
+    /// Returns an iterator over every `(variant, &value)` pair, in declaration order.
+    pub fn iter(&self) -> impl ::core::iter::Iterator<Item = (E, &V)> {
+        E::variants().iter().copied().zip(self.values.iter())
+    }
+}
+
 ///
 /// Constructs a "Fake Enum", that acts like a rust enum with unit variants,
 ///  but can accept invalid (undefined) variants without undefined behaviour.
@@ -14,7 +130,19 @@
 /// Two forms of this macro is provided. `enum name` declares an enum named "name". All the variants are declared with the same visibility as the type in the enclosing module.
 /// `enum struct name` declares an scoped enum named "name". The variants are declared `pub` within "name".
 ///
-/// In Both cases, it is valid to transmute the declared type to and from the repr type (note that no from implementation is provided)
+/// The `enum name` form also declares module-level `VARIANTS` and `variants()` alongside the
+///  variant constants, so two `enum name` declarations in the same module will collide on those
+///  names; use the `enum struct name` form (where they are associated with the type instead) if
+///  that is a problem.
+///
+/// In both cases, it is valid to transmute the declared type to and from the repr type. The
+///  generated type also provides `from_repr`/`repr` for a safe, total conversion to and from the
+///  repr type (every bit pattern is a valid value), `is_known` to check whether a value matches
+///  a declared variant, and `From` impls in both directions built on `from_repr`/`repr`.
+///
+/// A variant's discriminant may be omitted, in which case it is assigned the previous variant's
+///  value plus one (or `0`, for the first variant), the same as a C enum. Explicit and implicit
+///  discriminants may be freely mixed; an explicit value resets the count for following variants.
 ///
 /// ```rust
 /// use fake_enum::fake_enum;
@@ -22,7 +150,7 @@
 ///    #[repr(u8)] pub enum Foo{
 ///        Bar = 0,
 ///        Baz = 1,
-///    }  
+///    }
 /// };
 /// let x = Bar;
 /// assert_eq!(format!("{:?}",x),"Bar");
@@ -31,9 +159,66 @@
 #[macro_export]
 macro_rules! fake_enum{
     {#[repr($t:ty)] $(#[$meta:meta])* $vis:vis enum $name:ident {
-        $($item:ident = $expr:literal),*$(,)?
+        $($item:ident $(= $expr:expr)?),*$(,)?
     }} => {
+        $crate::__fake_enum_assign_enum!{[$t] [$($meta)*] [$vis] [$name] [] [] ; $($item $(= $expr)?),* }
+    };
+    {#[repr($t:ty)] $(#[$meta:meta])* $vis:vis enum struct $name:ident {
+        $($item:ident $(= $expr:expr)?),*$(,)?
+    }} => {
+        $crate::__fake_enum_assign_enum_struct!{[$t] [$($meta)*] [$vis] [$name] [] [] ; $($item $(= $expr)?),* }
+    };
+}
+
+///
+/// Resolves the implicit, auto-incrementing discriminants of a plain `enum` form [`fake_enum`]
+///  declaration into fully explicit `item = expr` pairs, one variant at a time, before handing
+///  off to [`__fake_enum_emit_enum`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fake_enum_assign_enum{
+    ([$t:ty] [$($meta:meta)*] [$vis:vis] [$name:ident] [$($out:tt)*] [$($prev:ident)?] ; $item:ident = $expr:expr $(, $($rest:tt)*)?) => {
+        $crate::__fake_enum_assign_enum!{[$t] [$($meta)*] [$vis] [$name] [$($out)* $item = ($expr),] [$item] ; $($($rest)*)? }
+    };
+    ([$t:ty] [$($meta:meta)*] [$vis:vis] [$name:ident] [$($out:tt)*] [$prev:ident] ; $item:ident $(, $($rest:tt)*)?) => {
+        $crate::__fake_enum_assign_enum!{[$t] [$($meta)*] [$vis] [$name] [$($out)* $item = ($prev.repr() + 1),] [$item] ; $($($rest)*)? }
+    };
+    ([$t:ty] [$($meta:meta)*] [$vis:vis] [$name:ident] [$($out:tt)*] [] ; $item:ident $(, $($rest:tt)*)?) => {
+        $crate::__fake_enum_assign_enum!{[$t] [$($meta)*] [$vis] [$name] [$($out)* $item = (0),] [$item] ; $($($rest)*)? }
+    };
+    ([$t:ty] [$($meta:meta)*] [$vis:vis] [$name:ident] [$($out:tt)*] [$($prev:ident)?] ; ) => {
+        $crate::__fake_enum_emit_enum!{[$t] [$($meta)*] [$vis] [$name] { $($out)* } }
+    };
+}
+
+///
+/// Resolves the implicit, auto-incrementing discriminants of an `enum struct` form [`fake_enum`]
+///  declaration into fully explicit `item = expr` pairs, one variant at a time, before handing
+///  off to [`__fake_enum_emit_enum_struct`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fake_enum_assign_enum_struct{
+    ([$t:ty] [$($meta:meta)*] [$vis:vis] [$name:ident] [$($out:tt)*] [$($prev:ident)?] ; $item:ident = $expr:expr $(, $($rest:tt)*)?) => {
+        $crate::__fake_enum_assign_enum_struct!{[$t] [$($meta)*] [$vis] [$name] [$($out)* $item = ($expr),] [$item] ; $($($rest)*)? }
+    };
+    ([$t:ty] [$($meta:meta)*] [$vis:vis] [$name:ident] [$($out:tt)*] [$prev:ident] ; $item:ident $(, $($rest:tt)*)?) => {
+        $crate::__fake_enum_assign_enum_struct!{[$t] [$($meta)*] [$vis] [$name] [$($out)* $item = ($name::$prev.repr() + 1),] [$item] ; $($($rest)*)? }
+    };
+    ([$t:ty] [$($meta:meta)*] [$vis:vis] [$name:ident] [$($out:tt)*] [] ; $item:ident $(, $($rest:tt)*)?) => {
+        $crate::__fake_enum_assign_enum_struct!{[$t] [$($meta)*] [$vis] [$name] [$($out)* $item = (0),] [$item] ; $($($rest)*)? }
+    };
+    ([$t:ty] [$($meta:meta)*] [$vis:vis] [$name:ident] [$($out:tt)*] [$($prev:ident)?] ; ) => {
+        $crate::__fake_enum_emit_enum_struct!{[$t] [$($meta)*] [$vis] [$name] { $($out)* } }
+    };
+}
 
+///
+/// Generates the body of a plain `enum` form [`fake_enum`], given a fully resolved list of
+///  `item = expr` pairs from [`__fake_enum_assign_enum`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fake_enum_emit_enum{
+    ([$t:ty] [$($meta:meta)*] [$vis:vis] [$name:ident] { $($item:ident = ($expr:expr)),*$(,)? }) => {
 
         #[derive(Copy,Clone,Eq,PartialEq)]
         #[repr(transparent)]
@@ -42,40 +227,605 @@ macro_rules! fake_enum{
 
         $(#[allow(non_upper_case_globals)] #[allow(dead_code)] $vis const $item: $name = $name($expr as $t);)*
 
+        /// Every variant declared on this fake enum, in declaration order.
+        $vis const VARIANTS: [$name; [$($item),*].len()] = [$($item),*];
+
+        /// Returns an iterator over every variant declared on this fake enum, in declaration order.
+        $vis fn variants() -> impl ::core::iter::Iterator<Item = $name>{
+            VARIANTS.into_iter()
+        }
+
+        impl $name{
+            /// Constructs a value of this fake enum from its representation. Since every bit
+            ///  pattern of the repr type is a valid value, this conversion is total and infallible.
+            pub const fn from_repr(v: $t) -> Self{
+                Self(v)
+            }
+
+            /// Returns the representation of this value.
+            pub const fn repr(self) -> $t{
+                self.0
+            }
+
+            /// Returns whether this value matches one of the declared variants.
+            #[allow(unreachable_patterns)]
+            #[allow(non_upper_case_globals)]
+            pub const fn is_known(self) -> bool{
+                match self{
+                    $($item => true,)*
+                    _ => false,
+                }
+            }
+
+            /// Returns the declared name of this value, or `None` if it does not match any
+            ///  declared variant.
+            #[allow(unreachable_patterns)]
+            #[allow(non_upper_case_globals)]
+            pub const fn name(self) -> Option<&'static str>{
+                match self{
+                    $($item => Some(::core::stringify!($item)),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl ::core::convert::From<$t> for $name{
+            fn from(v: $t) -> Self{
+                Self::from_repr(v)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $t{
+            fn from(v: $name) -> Self{
+                v.repr()
+            }
+        }
+
+        impl $crate::FakeEnum for $name{
+            fn variants() -> &'static [Self]{
+                &VARIANTS
+            }
+        }
+
         impl ::core::fmt::Debug for $name{
             #[allow(unreachable_patterns)]
+            #[allow(non_upper_case_globals)]
             fn fmt(&self,f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result{
-                match self{
-                    $(Self($expr) => f.write_str(::core::stringify!($item)),)*
+                match *self{
+                    $($item => f.write_str(::core::stringify!($item)),)*
+                    e => f.write_fmt(::core::format_args!("{}({})",::core::stringify!($name),e.0))
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for $name{
+            #[allow(unreachable_patterns)]
+            #[allow(non_upper_case_globals)]
+            fn fmt(&self,f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result{
+                match *self{
+                    $($item => f.write_str(::core::stringify!($item)),)*
                     e => f.write_fmt(::core::format_args!("{}({})",::core::stringify!($name),e.0))
                 }
             }
         }
+
+        impl ::core::str::FromStr for $name{
+            type Err = $crate::ParseEnumError;
+
+            #[allow(unreachable_patterns)]
+            fn from_str(s: &str) -> ::core::result::Result<Self,Self::Err>{
+                $(if s == ::core::stringify!($item){
+                    return ::core::result::Result::Ok($item);
+                })*
+                const NAME: &str = ::core::stringify!($name);
+                if s.len() > NAME.len() + 2 && s.starts_with(NAME) && s.as_bytes()[NAME.len()] == b'(' && s.ends_with(')'){
+                    let inner = &s[NAME.len()+1..s.len()-1];
+                    if let ::core::result::Result::Ok(v) = inner.parse::<$t>(){
+                        return ::core::result::Result::Ok(Self(v));
+                    }
+                }
+                ::core::result::Result::Err($crate::ParseEnumError::__new(s))
+            }
+        }
+
+        $crate::__fake_enum_serde_enum!{[$t] [$name] { $($item),* } }
     };
-    {#[repr($t:ty)] $(#[$meta:meta])* $vis:vis enum struct $name:ident {
-        $($item:ident = $expr:literal),*$(,)?
-    }} => {
+}
+
+///
+/// Generates the body of an `enum struct` form [`fake_enum`], given a fully resolved list of
+///  `item = expr` pairs from [`__fake_enum_assign_enum_struct`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fake_enum_emit_enum_struct{
+    ([$t:ty] [$($meta:meta)*] [$vis:vis] [$name:ident] { $($item:ident = ($expr:expr)),*$(,)? }) => {
         #[derive(Copy,Clone,Eq,PartialEq)]
         #[repr(transparent)]
         $(#[$meta])*
         $vis struct $name($t);
         impl $name{
             $(#[allow(non_upper_case_globals)] #[allow(dead_code)] pub const $item: $name = $name($expr as $t);)*
-        }
-        impl ::std::fmt::Debug for $name{
+
+            /// Constructs a value of this fake enum from its representation. Since every bit
+            ///  pattern of the repr type is a valid value, this conversion is total and infallible.
+            pub const fn from_repr(v: $t) -> Self{
+                Self(v)
+            }
+
+            /// Returns the representation of this value.
+            pub const fn repr(self) -> $t{
+                self.0
+            }
+
+            /// Returns whether this value matches one of the declared variants.
+            #[allow(unreachable_patterns)]
+            pub const fn is_known(self) -> bool{
+                match self{
+                    $(Self::$item => true,)*
+                    _ => false,
+                }
+            }
+
+            /// Returns the declared name of this value, or `None` if it does not match any
+            ///  declared variant.
             #[allow(unreachable_patterns)]
-            fn fmt(&self,f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result{
+            pub const fn name(self) -> Option<&'static str>{
                 match self{
-                    $(Self($expr) => f.write_str(::std::stringify!($item)),)*
+                    $(Self::$item => Some(::core::stringify!($item)),)*
+                    _ => None,
+                }
+            }
+
+            /// Every variant declared on this fake enum, in declaration order.
+            pub const VARIANTS: [$name; [$(Self::$item),*].len()] = [$(Self::$item),*];
+
+            /// Returns an iterator over every variant declared on this fake enum, in declaration order.
+            pub fn variants() -> impl ::core::iter::Iterator<Item = $name>{
+                Self::VARIANTS.into_iter()
+            }
+        }
+
+        impl ::core::convert::From<$t> for $name{
+            fn from(v: $t) -> Self{
+                Self::from_repr(v)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $t{
+            fn from(v: $name) -> Self{
+                v.repr()
+            }
+        }
+
+        impl $crate::FakeEnum for $name{
+            fn variants() -> &'static [Self]{
+                &Self::VARIANTS
+            }
+        }
+
+        impl ::core::fmt::Debug for $name{
+            #[allow(unreachable_patterns)]
+            fn fmt(&self,f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result{
+                match *self{
+                    $(Self::$item => f.write_str(::core::stringify!($item)),)*
                     e => f.write_fmt(::core::format_args!("{}({})",::core::stringify!($name),e.0))
                 }
             }
         }
-    }
+
+        impl ::core::fmt::Display for $name{
+            #[allow(unreachable_patterns)]
+            fn fmt(&self,f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result{
+                match *self{
+                    $(Self::$item => f.write_str(::core::stringify!($item)),)*
+                    e => f.write_fmt(::core::format_args!("{}({})",::core::stringify!($name),e.0))
+                }
+            }
+        }
+
+        impl ::core::str::FromStr for $name{
+            type Err = $crate::ParseEnumError;
+
+            #[allow(unreachable_patterns)]
+            fn from_str(s: &str) -> ::core::result::Result<Self,Self::Err>{
+                $(if s == ::core::stringify!($item){
+                    return ::core::result::Result::Ok(Self::$item);
+                })*
+                const NAME: &str = ::core::stringify!($name);
+                if s.len() > NAME.len() + 2 && s.starts_with(NAME) && s.as_bytes()[NAME.len()] == b'(' && s.ends_with(')'){
+                    let inner = &s[NAME.len()+1..s.len()-1];
+                    if let ::core::result::Result::Ok(v) = inner.parse::<$t>(){
+                        return ::core::result::Result::Ok(Self(v));
+                    }
+                }
+                ::core::result::Result::Err($crate::ParseEnumError::__new(s))
+            }
+        }
+
+        $crate::__fake_enum_serde_enum_struct!{[$t] [$name] { $($item),* } }
+    };
+}
+
+///
+/// Generates the `Serialize`/`Deserialize` impls for a plain `enum` form [`fake_enum`], when the
+///  `serde` feature is enabled on this crate.
+///
+/// This is a separate, internal macro (rather than a `#[cfg(feature = "serde")]` on the impls
+///  directly in [`__fake_enum_emit_enum`]) because a `cfg` written inside a `macro_rules!` body
+///  is evaluated against the *invoking* crate's features, not `fake_enum`'s own. Gating which of
+///  two macro definitions exists via an item-level `#[cfg]` instead evaluates against this
+///  crate's features, so downstream crates that enable `fake_enum/serde` get the impls (and
+///  downstream crates that don't, don't), regardless of whether they happen to declare a
+///  `serde` feature of their own. The impls also reference serde through `$crate::__private`
+///  rather than an absolute `::serde` path, so enabling the feature doesn't also require
+///  downstream crates to add `serde` as a direct dependency.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "serde")]
+macro_rules! __fake_enum_serde_enum{
+    ([$t:ty] [$name:ident] { $($item:ident),*$(,)? }) => {
+        impl $crate::__private::serde::Serialize for $name{
+            fn serialize<S: $crate::__private::serde::Serializer>(&self, serializer: S) -> ::core::result::Result<S::Ok,S::Error>{
+                match self.name(){
+                    ::core::option::Option::Some(name) => serializer.serialize_str(name),
+                    ::core::option::Option::None => <$t as $crate::__private::serde::Serialize>::serialize(&self.0,serializer),
+                }
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name{
+            fn deserialize<D: $crate::__private::serde::Deserializer<'de>>(deserializer: D) -> ::core::result::Result<Self,D::Error>{
+                struct Visitor;
+
+                impl<'de> $crate::__private::serde::de::Visitor<'de> for Visitor{
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result{
+                        f.write_fmt(::core::format_args!(
+                            "a variant name or representation value of {}",
+                            ::core::stringify!($name)
+                        ))
+                    }
+
+                    #[allow(unreachable_patterns)]
+                    fn visit_str<E: $crate::__private::serde::de::Error>(self, v: &str) -> ::core::result::Result<Self::Value,E>{
+                        match v{
+                            $(::core::stringify!($item) => ::core::result::Result::Ok($item),)*
+                            _ => ::core::result::Result::Err(E::unknown_variant(v,&[$(::core::stringify!($item)),*])),
+                        }
+                    }
+
+                    fn visit_u64<E: $crate::__private::serde::de::Error>(self, v: u64) -> ::core::result::Result<Self::Value,E>{
+                        ::core::result::Result::Ok($name::from_repr(v as $t))
+                    }
+
+                    fn visit_i64<E: $crate::__private::serde::de::Error>(self, v: i64) -> ::core::result::Result<Self::Value,E>{
+                        ::core::result::Result::Ok($name::from_repr(v as $t))
+                    }
+                }
+
+                deserializer.deserialize_any(Visitor)
+            }
+        }
+    };
+}
+
+/// No-op stand-in for [`__fake_enum_serde_enum`] used when this crate's `serde` feature is
+///  disabled, so that [`__fake_enum_emit_enum`] can call it unconditionally.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "serde"))]
+macro_rules! __fake_enum_serde_enum{
+    ($($tt:tt)*) => {};
+}
+
+///
+/// Generates the `Serialize`/`Deserialize` impls for an `enum struct` form [`fake_enum`], when
+///  the `serde` feature is enabled on this crate. See [`__fake_enum_serde_enum`] for why this is
+///  a separate, feature-gated macro rather than a `#[cfg(feature = "serde")]` on the impls.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "serde")]
+macro_rules! __fake_enum_serde_enum_struct{
+    ([$t:ty] [$name:ident] { $($item:ident),*$(,)? }) => {
+        impl $crate::__private::serde::Serialize for $name{
+            fn serialize<S: $crate::__private::serde::Serializer>(&self, serializer: S) -> ::core::result::Result<S::Ok,S::Error>{
+                match self.name(){
+                    ::core::option::Option::Some(name) => serializer.serialize_str(name),
+                    ::core::option::Option::None => <$t as $crate::__private::serde::Serialize>::serialize(&self.0,serializer),
+                }
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name{
+            fn deserialize<D: $crate::__private::serde::Deserializer<'de>>(deserializer: D) -> ::core::result::Result<Self,D::Error>{
+                struct Visitor;
+
+                impl<'de> $crate::__private::serde::de::Visitor<'de> for Visitor{
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result{
+                        f.write_fmt(::core::format_args!(
+                            "a variant name or representation value of {}",
+                            ::core::stringify!($name)
+                        ))
+                    }
+
+                    #[allow(unreachable_patterns)]
+                    fn visit_str<E: $crate::__private::serde::de::Error>(self, v: &str) -> ::core::result::Result<Self::Value,E>{
+                        match v{
+                            $(::core::stringify!($item) => ::core::result::Result::Ok($name::$item),)*
+                            _ => ::core::result::Result::Err(E::unknown_variant(v,&[$(::core::stringify!($item)),*])),
+                        }
+                    }
+
+                    fn visit_u64<E: $crate::__private::serde::de::Error>(self, v: u64) -> ::core::result::Result<Self::Value,E>{
+                        ::core::result::Result::Ok($name::from_repr(v as $t))
+                    }
+
+                    fn visit_i64<E: $crate::__private::serde::de::Error>(self, v: i64) -> ::core::result::Result<Self::Value,E>{
+                        ::core::result::Result::Ok($name::from_repr(v as $t))
+                    }
+                }
+
+                deserializer.deserialize_any(Visitor)
+            }
+        }
+    };
+}
+
+/// No-op stand-in for [`__fake_enum_serde_enum_struct`] used when this crate's `serde` feature
+///  is disabled, so that [`__fake_enum_emit_enum_struct`] can call it unconditionally.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "serde"))]
+macro_rules! __fake_enum_serde_enum_struct{
+    ($($tt:tt)*) => {};
+}
+
+///
+/// Declares a fake-enum-like "bitflag set" type: a `#[repr(transparent)]` newtype over `$t`
+///  whose declared constants are individual flags (or useful combinations of flags) that are
+///  meant to be OR-ed together, rather than exclusive variants of a C-style enum.
+///
+/// Unlike [`fake_enum`], flags are not required to be contiguous or sequential, so every flag
+///  must be given an explicit value (commonly a literal or a `1 << n` shift expression).
+///
+/// As with [`fake_enum`], two forms are supported: a bare `enum`, whose flags are declared as
+///  top level consts, and an `enum struct`, whose flags are declared as associated consts of
+///  the set type.
+///
+/// The bare `enum` form also declares a module-level `FLAGS` constant alongside the flag
+///  constants, so two bare `enum` declarations in the same module will collide on that name; use
+///  the `enum struct` form (where it is associated with the type instead) if that is a problem.
+///
+/// ```rust
+/// use fake_enum::fake_enum_set;
+/// fake_enum_set!{
+///     #[repr(u8)] pub enum struct Perms{
+///         Read = 1 << 0,
+///         Write = 1 << 1,
+///         Execute = 1 << 2,
+///     }
+/// }
+/// let mut p = Perms::Read | Perms::Write;
+/// assert!(p.contains(Perms::Read));
+/// assert!(!p.contains(Perms::Execute));
+/// p.remove(Perms::Read);
+/// assert_eq!(p, Perms::Write);
+/// ```
+#[macro_export]
+macro_rules! fake_enum_set{
+    {#[repr($t:ty)] $(#[$meta:meta])* $vis:vis enum $name:ident {
+        $($item:ident = $expr:expr),*$(,)?
+    }} => {
+        #[derive(Copy,Clone,Eq,PartialEq,Debug)]
+        #[repr(transparent)]
+        $(#[$meta])*
+        $vis struct $name($t);
+
+        $(#[allow(non_upper_case_globals)] #[allow(dead_code)] $vis const $item: $name = $name($expr as $t);)*
+
+        /// Every flag declared on this fake enum set, in declaration order.
+        $vis const FLAGS: [$name; [$($item),*].len()] = [$($item),*];
+
+        impl $name{
+            /// Constructs a flag set directly from its representation, without requiring that
+            ///  every set bit correspond to a declared flag.
+            pub const fn from_repr(v: $t) -> Self{
+                Self(v)
+            }
+
+            /// Returns the representation of this flag set.
+            pub const fn repr(self) -> $t{
+                self.0
+            }
+
+            /// Returns the empty flag set, with no bits set.
+            pub const fn empty() -> Self{
+                Self(0 as $t)
+            }
+
+            /// Returns the flag set containing every declared flag, ORed together.
+            pub const fn all() -> Self{
+                Self(0 as $t $(| ($expr as $t))*)
+            }
+
+            /// Returns whether every bit set in `flag` is also set in `self`.
+            pub const fn contains(self, flag: Self) -> bool{
+                (self.0 & flag.0) == flag.0
+            }
+
+            /// Sets every bit that is set in `flag`.
+            pub fn insert(&mut self, flag: Self){
+                self.0 |= flag.0;
+            }
+
+            /// Clears every bit that is set in `flag`.
+            pub fn remove(&mut self, flag: Self){
+                self.0 &= !flag.0;
+            }
+
+            /// Returns the bits set in `self` that do not belong to any declared flag.
+            pub const fn unknown_bits(self) -> $t{
+                self.0 & !Self::all().0
+            }
+
+            /// Returns an iterator over the declared flags present in this set, skipping any
+            ///  unrecognized bits (see [`unknown_bits`](Self::unknown_bits)).
+            pub fn iter(self) -> impl ::core::iter::Iterator<Item = $name>{
+                FLAGS.into_iter().filter(move |f| self.contains(*f))
+            }
+        }
+
+        impl ::core::ops::BitOr for $name{
+            type Output = Self;
+            fn bitor(self, rhs: Self) -> Self{
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl ::core::ops::BitAnd for $name{
+            type Output = Self;
+            fn bitand(self, rhs: Self) -> Self{
+                Self(self.0 & rhs.0)
+            }
+        }
+
+        impl ::core::ops::BitXor for $name{
+            type Output = Self;
+            fn bitxor(self, rhs: Self) -> Self{
+                Self(self.0 ^ rhs.0)
+            }
+        }
+
+        impl ::core::ops::Not for $name{
+            type Output = Self;
+            fn not(self) -> Self{
+                Self(!self.0)
+            }
+        }
+
+        impl ::core::ops::BitOrAssign for $name{
+            fn bitor_assign(&mut self, rhs: Self){
+                self.0 |= rhs.0;
+            }
+        }
+
+        impl ::core::ops::BitAndAssign for $name{
+            fn bitand_assign(&mut self, rhs: Self){
+                self.0 &= rhs.0;
+            }
+        }
+    };
+    {#[repr($t:ty)] $(#[$meta:meta])* $vis:vis enum struct $name:ident {
+        $($item:ident = $expr:expr),*$(,)?
+    }} => {
+        #[derive(Copy,Clone,Eq,PartialEq,Debug)]
+        #[repr(transparent)]
+        $(#[$meta])*
+        $vis struct $name($t);
+
+        impl $name{
+            $(#[allow(non_upper_case_globals)] #[allow(dead_code)] pub const $item: $name = $name($expr as $t);)*
+
+            /// Every flag declared on this fake enum set, in declaration order.
+            pub const FLAGS: [$name; [$(Self::$item),*].len()] = [$(Self::$item),*];
+
+            /// Constructs a flag set directly from its representation, without requiring that
+            ///  every set bit correspond to a declared flag.
+            pub const fn from_repr(v: $t) -> Self{
+                Self(v)
+            }
+
+            /// Returns the representation of this flag set.
+            pub const fn repr(self) -> $t{
+                self.0
+            }
+
+            /// Returns the empty flag set, with no bits set.
+            pub const fn empty() -> Self{
+                Self(0 as $t)
+            }
+
+            /// Returns the flag set containing every declared flag, ORed together.
+            pub const fn all() -> Self{
+                Self(0 as $t $(| (Self::$item.0))*)
+            }
+
+            /// Returns whether every bit set in `flag` is also set in `self`.
+            pub const fn contains(self, flag: Self) -> bool{
+                (self.0 & flag.0) == flag.0
+            }
+
+            /// Sets every bit that is set in `flag`.
+            pub fn insert(&mut self, flag: Self){
+                self.0 |= flag.0;
+            }
+
+            /// Clears every bit that is set in `flag`.
+            pub fn remove(&mut self, flag: Self){
+                self.0 &= !flag.0;
+            }
+
+            /// Returns the bits set in `self` that do not belong to any declared flag.
+            pub const fn unknown_bits(self) -> $t{
+                self.0 & !Self::all().0
+            }
+
+            /// Returns an iterator over the declared flags present in this set, skipping any
+            ///  unrecognized bits (see [`unknown_bits`](Self::unknown_bits)).
+            pub fn iter(self) -> impl ::core::iter::Iterator<Item = $name>{
+                Self::FLAGS.into_iter().filter(move |f| self.contains(*f))
+            }
+        }
+
+        impl ::core::ops::BitOr for $name{
+            type Output = Self;
+            fn bitor(self, rhs: Self) -> Self{
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl ::core::ops::BitAnd for $name{
+            type Output = Self;
+            fn bitand(self, rhs: Self) -> Self{
+                Self(self.0 & rhs.0)
+            }
+        }
+
+        impl ::core::ops::BitXor for $name{
+            type Output = Self;
+            fn bitxor(self, rhs: Self) -> Self{
+                Self(self.0 ^ rhs.0)
+            }
+        }
+
+        impl ::core::ops::Not for $name{
+            type Output = Self;
+            fn not(self) -> Self{
+                Self(!self.0)
+            }
+        }
+
+        impl ::core::ops::BitOrAssign for $name{
+            fn bitor_assign(&mut self, rhs: Self){
+                self.0 |= rhs.0;
+            }
+        }
+
+        impl ::core::ops::BitAndAssign for $name{
+            fn bitand_assign(&mut self, rhs: Self){
+                self.0 &= rhs.0;
+            }
+        }
+    };
 }
 
 #[cfg(test)]
 mod test {
+    use super::{EnumMap, ParseEnumError};
+
     fake_enum! {
         #[repr(u16)] pub enum ElfType{
             ET_NONE = 0,
@@ -114,6 +864,78 @@ mod test {
         assert_eq!(ET_CORE, ET_CORE);
     }
 
+    #[test]
+    pub fn fake_enum_repr_conversions() {
+        assert_eq!(ElfType::from_repr(1), ET_REL);
+        assert_eq!(ElfType::from(1u16), ET_REL);
+        assert_eq!(ET_REL.repr(), 1u16);
+        assert_eq!(u16::from(ET_REL), 1u16);
+        assert!(ET_NONE.is_known());
+        assert!(ET_CORE.is_known());
+        assert!(!ElfType::from_repr(42).is_known());
+    }
+
+    #[test]
+    pub fn fake_enum_variants_and_name() {
+        assert_eq!(VARIANTS, [ET_NONE, ET_REL, ET_EXEC, ET_DYN, ET_CORE]);
+        assert_eq!(variants().count(), 5);
+        assert!(variants().eq(VARIANTS));
+        assert_eq!(ET_REL.name(), Some("ET_REL"));
+        assert_eq!(ElfType::from_repr(42).name(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    pub fn fake_enum_serde_impl() {
+        assert_eq!(serde_json::to_string(&ET_REL).unwrap(), "\"ET_REL\"");
+        assert_eq!(
+            serde_json::to_string(&ElfType::from_repr(42)).unwrap(),
+            "42"
+        );
+        assert_eq!(
+            serde_json::from_str::<ElfType>("\"ET_REL\"").unwrap(),
+            ET_REL
+        );
+        assert_eq!(
+            serde_json::from_str::<ElfType>("42").unwrap(),
+            ElfType::from_repr(42)
+        );
+        assert!(serde_json::from_str::<ElfType>("\"nonsense\"").is_err());
+    }
+
+    #[test]
+    pub fn fake_enum_display_impl() {
+        assert_eq!(format!("{}", ET_NONE), "ET_NONE");
+        assert_eq!(format!("{}", ET_REL), "ET_REL");
+        assert_eq!(
+            format!("{}", unsafe { std::mem::transmute::<u16, ElfType>(42) }),
+            "ElfType(42)"
+        );
+    }
+
+    #[test]
+    pub fn fake_enum_from_str_impl() {
+        use std::str::FromStr;
+
+        assert_eq!(ElfType::from_str("ET_NONE"), Ok(ET_NONE));
+        assert_eq!(ElfType::from_str("ET_REL"), Ok(ET_REL));
+        assert_eq!(
+            ElfType::from_str("ElfType(42)"),
+            Ok(unsafe { std::mem::transmute::<u16, ElfType>(42) })
+        );
+        assert!(ElfType::from_str("nonsense").is_err());
+    }
+
+    #[test]
+    pub fn parse_enum_error_truncates_on_char_boundary() {
+        let mut s = "a".repeat(31);
+        s.push('é');
+        assert_eq!(s.len(), 33);
+
+        let err = ParseEnumError::__new(&s);
+        assert_eq!(err.as_str(), "a".repeat(31));
+    }
+
     #[test]
     pub fn fake_enum_transmute_test() {
         assert_eq!(unsafe { std::mem::transmute::<u16, ElfType>(0) }, ET_NONE);
@@ -123,6 +945,34 @@ mod test {
         assert_eq!(unsafe { std::mem::transmute::<u16, ElfType>(4) }, ET_CORE);
     }
 
+    fake_enum! {
+        #[repr(u8)] pub enum struct Weekday{
+            Monday = 1,
+            Tuesday,
+            Wednesday,
+            Thursday,
+            Friday,
+            Saturday,
+            Sunday,
+            Weekend = 10,
+            AnotherWeekend,
+        }
+    }
+
+    #[test]
+    pub fn fake_enum_implicit_discriminants() {
+        assert_eq!(Weekday::Monday.repr(), 1u8);
+        assert_eq!(Weekday::Tuesday.repr(), 2u8);
+        assert_eq!(Weekday::Wednesday.repr(), 3u8);
+        assert_eq!(Weekday::Thursday.repr(), 4u8);
+        assert_eq!(Weekday::Friday.repr(), 5u8);
+        assert_eq!(Weekday::Saturday.repr(), 6u8);
+        assert_eq!(Weekday::Sunday.repr(), 7u8);
+        assert_eq!(Weekday::Weekend.repr(), 10u8);
+        assert_eq!(Weekday::AnotherWeekend.repr(), 11u8);
+        assert_eq!(format!("{:?}", Weekday::Friday), "Friday");
+    }
+
     fake_enum! {
         #[repr(u8)]
         #[derive(Hash,Default)]
@@ -145,4 +995,67 @@ mod test {
             Uuid = 15
         }
     }
+
+    fake_enum_set! {
+        #[repr(u8)] pub enum struct Perms{
+            Read = 1 << 0,
+            Write = 1 << 1,
+            Execute = 1 << 2,
+        }
+    }
+
+    #[test]
+    pub fn fake_enum_set_bitops() {
+        let mut p = Perms::Read | Perms::Write;
+        assert_eq!(p.repr(), 0b011);
+        assert!(p.contains(Perms::Read));
+        assert!(p.contains(Perms::Write));
+        assert!(!p.contains(Perms::Execute));
+        assert!(p.contains(Perms::Read | Perms::Write));
+
+        p.insert(Perms::Execute);
+        assert_eq!(p, Perms::all());
+
+        p.remove(Perms::Read);
+        assert_eq!(p, Perms::Write | Perms::Execute);
+
+        assert_eq!(Perms::empty().repr(), 0);
+        assert_eq!((Perms::Read ^ Perms::Read), Perms::empty());
+        assert_eq!(!Perms::empty(), Perms::from_repr(0xFF));
+    }
+
+    #[test]
+    pub fn fake_enum_set_iter_and_unknown_bits() {
+        let known = Perms::Read | Perms::Execute;
+        assert!(known.iter().eq([Perms::Read, Perms::Execute]));
+
+        let with_unknown = Perms::from_repr(known.repr() | 0b1000_0000);
+        assert!(with_unknown.iter().eq([Perms::Read, Perms::Execute]));
+        assert_eq!(with_unknown.unknown_bits(), 0b1000_0000);
+    }
+
+    #[test]
+    pub fn fake_enum_map_lookup() {
+        static NAMES: EnumMap<ElfType, &'static str, 5> =
+            EnumMap::new(["none", "relocatable", "executable", "shared", "core"]);
+
+        assert_eq!(NAMES.get(ET_NONE), Some(&"none"));
+        assert_eq!(NAMES.get(ET_DYN), Some(&"shared"));
+        assert_eq!(NAMES.get(ElfType::from_repr(42)), None);
+
+        let mut counts: EnumMap<ElfType, u32, 5> = EnumMap::new([0; 5]);
+        *counts.get_mut(ET_EXEC).unwrap() += 1;
+        assert_eq!(counts.get(ET_EXEC), Some(&1));
+        assert_eq!(counts.get(ET_REL), Some(&0));
+
+        assert!(NAMES
+            .iter()
+            .eq([
+                (ET_NONE, &"none"),
+                (ET_REL, &"relocatable"),
+                (ET_EXEC, &"executable"),
+                (ET_DYN, &"shared"),
+                (ET_CORE, &"core"),
+            ]));
+    }
 }